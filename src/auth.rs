@@ -0,0 +1,115 @@
+use axum::http::{Request, Response, StatusCode, header};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use subtle::ConstantTimeEq;
+use tower::{Layer, Service};
+
+/// Identity established by a successfully authenticated request, threaded
+/// through to `ServerHandler::initialize` via the same `axum::http::request::Parts`
+/// extension mechanism it already consults.
+#[derive(Clone)]
+pub struct AuthenticatedIdentity {
+    pub token: String,
+}
+
+impl std::fmt::Debug for AuthenticatedIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthenticatedIdentity")
+            .field("token", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Tower layer that requires a `Authorization: Bearer <token>` header matching
+/// `expected_token` before letting a request through, returning `401` otherwise.
+/// When `expected_token` is `None` every request is let through unchanged,
+/// preserving the server's existing unauthenticated behavior.
+#[derive(Clone)]
+pub struct BearerAuthLayer {
+    expected_token: Arc<Option<String>>,
+}
+
+impl BearerAuthLayer {
+    pub fn new(expected_token: Option<String>) -> Self {
+        Self {
+            expected_token: Arc::new(expected_token),
+        }
+    }
+}
+
+impl<S> Layer<S> for BearerAuthLayer {
+    type Service = BearerAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BearerAuthService {
+            inner,
+            expected_token: self.expected_token.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BearerAuthService<S> {
+    inner: S,
+    expected_token: Arc<Option<String>>,
+}
+
+impl<S, ReqBody> Service<Request<ReqBody>> for BearerAuthService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<axum::body::Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = Response<axum::body::Body>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let expected_token = self.expected_token.clone();
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            let Some(expected_token) = expected_token.as_ref() else {
+                return inner.call(req).await;
+            };
+
+            if !bearer_token_matches(&req, expected_token) {
+                return Ok(unauthorized_response());
+            }
+
+            req.extensions_mut().insert(AuthenticatedIdentity {
+                token: expected_token.clone(),
+            });
+
+            inner.call(req).await
+        })
+    }
+}
+
+fn bearer_token_matches<ReqBody>(req: &Request<ReqBody>, expected_token: &str) -> bool {
+    let Some(header_value) = req.headers().get(header::AUTHORIZATION) else {
+        return false;
+    };
+    let Ok(header_value) = header_value.to_str() else {
+        return false;
+    };
+    let Some(provided_token) = header_value.strip_prefix("Bearer ") else {
+        return false;
+    };
+
+    provided_token.as_bytes().ct_eq(expected_token.as_bytes()).into()
+}
+
+fn unauthorized_response() -> Response<axum::body::Body> {
+    Response::builder()
+        .status(StatusCode::UNAUTHORIZED)
+        .body(axum::body::Body::from("unauthorized"))
+        .expect("static response is always valid")
+}
@@ -12,6 +12,8 @@ use rmcp::transport::streamable_http_server::{
 };
 use clap::Parser;
 use rmcp::service::{QuitReason, ServerInitializeError};
+use tower::ServiceBuilder;
+use yao_mcp::auth::BearerAuthLayer;
 #[cfg(feature = "stdio")]
 use rmcp::ServiceExt;
 #[cfg(feature = "stdio")]
@@ -21,7 +23,12 @@ use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::{fmt, Registry};
 use tracing_subscriber::util::{SubscriberInitExt, TryInitError};
 use yao_mcp::mcp::tools::ObsidianMCP;
+use yao_mcp::vault::backend::{LocalBackend, VaultBackend};
+use yao_mcp::vault::object_store_backend::{ObjectStoreBackend, ObjectStoreCredentials};
+use yao_mcp::vault::policy::Policy;
+use yao_mcp::vault::search::SearchIndex;
 use yao_mcp::vault::vault::Vault;
+use yao_mcp::vault::watcher::VaultWatcher;
 
 #[derive(Debug)]
 pub enum AppError {
@@ -95,8 +102,10 @@ impl AppError {
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct ApplicationArgs {
-    /// Path to the Obsidian vault
-    #[arg(short, long, env = "OBSIDIAN_VAULT_PATH", required = true)]
+    /// Path to the Obsidian vault. Required for the local backend; optional
+    /// for the s3/gcs backends, which don't live on a local filesystem and
+    /// only use it as a virtual root for normalizing note keys (default `/`).
+    #[arg(short, long, env = "OBSIDIAN_VAULT_PATH")]
     vault_path: Option<PathBuf>,
 
     /// MCP Transport mode (stdio, http)
@@ -106,13 +115,129 @@ struct ApplicationArgs {
     /// MCP HTTP server port (for http transport)
     #[arg(long, default_value = "3000", env = "MCP_HTTP_TRANSPORT_PORT")]
     port: u16,
+
+    /// Vault storage backend (local, s3, gcs)
+    #[arg(long, default_value = "local", env = "MCP_VAULT_BACKEND")]
+    backend: String,
+
+    /// Bucket name for the s3/gcs backend
+    #[arg(long, env = "MCP_VAULT_BUCKET")]
+    bucket: Option<String>,
+
+    /// Object key prefix for the s3/gcs backend
+    #[arg(long, default_value = "", env = "MCP_VAULT_PREFIX")]
+    prefix: String,
+
+    /// Endpoint for the s3/gcs backend
+    #[arg(long, env = "MCP_VAULT_ENDPOINT")]
+    endpoint: Option<String>,
+
+    /// Bearer token required to access the HTTP transport (optional)
+    #[arg(long, env = "MCP_AUTH_TOKEN")]
+    auth_token: Option<String>,
+
+    /// Path to a PEM-encoded TLS certificate (enables TLS, requires --tls-key)
+    #[arg(long, env = "MCP_TLS_CERT")]
+    tls_cert: Option<PathBuf>,
+
+    /// Path to a PEM-encoded TLS private key (enables TLS, requires --tls-cert)
+    #[arg(long, env = "MCP_TLS_KEY")]
+    tls_key: Option<PathBuf>,
+
+    /// Path to a TOML capability policy restricting which operations/paths are permitted
+    #[arg(long, env = "MCP_POLICY_PATH")]
+    policy: Option<PathBuf>,
+
+    /// Shorthand for a policy that denies all writes
+    #[arg(long, default_value_t = false, env = "MCP_READ_ONLY")]
+    read_only: bool,
+}
+
+fn build_policy(args: &ApplicationArgs) -> Result<Option<Arc<Policy>>, AppError> {
+    match (&args.policy, args.read_only) {
+        (Some(_), true) => Err(AppError::argument_error("--policy and --read-only are mutually exclusive")),
+        (Some(path), false) => {
+            let policy = Policy::from_file(path).map_err(|err| AppError::argument_error(err.to_string()))?;
+            Ok(Some(Arc::new(policy)))
+        }
+        (None, true) => Ok(Some(Arc::new(Policy::read_only()))),
+        (None, false) => Ok(None),
+    }
+}
+
+fn load_tls_acceptor(cert_path: &PathBuf, key_path: &PathBuf) -> Result<tokio_rustls::TlsAcceptor, AppError> {
+    let certs = rustls_pemfile::certs(&mut io::BufReader::new(std::fs::File::open(cert_path)?))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let key = rustls_pemfile::private_key(&mut io::BufReader::new(std::fs::File::open(key_path)?))?
+        .ok_or_else(|| AppError::argument_error("no private key found in --tls-key file"))?;
+
+    let config = rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|err| AppError::unexpected_error(err.to_string()))?;
+
+    Ok(tokio_rustls::TlsAcceptor::from(Arc::new(config)))
+}
+
+fn build_backend(args: &ApplicationArgs, vault_path: &PathBuf) -> Result<Arc<dyn VaultBackend>, AppError> {
+    match args.backend.as_str() {
+        "local" => Ok(Arc::new(LocalBackend::new(vault_path.clone()))),
+
+        "s3" | "gcs" => {
+            let bucket = args.bucket.clone().ok_or_else(|| {
+                AppError::argument_error(format!("--bucket is required for the {} backend", args.backend))
+            })?;
+            let endpoint = args.endpoint.clone().ok_or_else(|| {
+                AppError::argument_error(format!("--endpoint is required for the {} backend", args.backend))
+            })?;
+            let access_key = std::env::var("MCP_VAULT_ACCESS_KEY").map_err(|_| {
+                AppError::argument_error("MCP_VAULT_ACCESS_KEY must be set for the s3/gcs backend")
+            })?;
+            let secret_key = std::env::var("MCP_VAULT_SECRET_KEY").map_err(|_| {
+                AppError::argument_error("MCP_VAULT_SECRET_KEY must be set for the s3/gcs backend")
+            })?;
+
+            Ok(Arc::new(ObjectStoreBackend::new(
+                endpoint,
+                bucket,
+                args.prefix.clone(),
+                ObjectStoreCredentials { access_key, secret_key },
+            )))
+        }
+
+        backend => Err(AppError::argument_error(format!(
+            "Unknown backend '{}'. Valid options: local,s3,gcs",
+            backend
+        ))),
+    }
+}
+
+/// Subscribe `search` to `watcher`'s debounced changed-path batches, so notes
+/// edited externally (e.g. from the Obsidian desktop app) invalidate the
+/// shared search index the same way an MCP write does.
+fn spawn_search_invalidation_task(watcher: Arc<VaultWatcher>, search: Arc<SearchIndex>) {
+    let mut changes = watcher.subscribe();
+
+    tokio::spawn(async move {
+        while let Ok(changed_paths) = changes.recv().await {
+            for path in &changed_paths {
+                search.invalidate(path).await;
+            }
+        }
+    });
 }
 
 #[cfg(feature = "stdio")]
-async fn start_stdio_server(vault : Arc<Vault>) -> Result<QuitReason, AppError> {
+async fn start_stdio_server(
+    vault: Arc<Vault>,
+    watcher: Arc<VaultWatcher>,
+    search: Arc<SearchIndex>,
+    policy: Option<Arc<Policy>>,
+) -> Result<QuitReason, AppError> {
     log::info!("Starting MCP server in STDIO mode. Use Ctrl+C to exit.");
     let service=
-        ObsidianMCP::new(vault)
+        ObsidianMCP::new(vault, watcher, search, policy)
             .serve(stdio())
             .await
             .inspect_err(|e| { tracing::error!("serving error: {:?}", e); })?;
@@ -121,32 +246,64 @@ async fn start_stdio_server(vault : Arc<Vault>) -> Result<QuitReason, AppError>
 }
 
 #[cfg(feature = "http")]
-async fn start_http_server(vault : Arc<Vault>, port : u16) -> Result<QuitReason, AppError> {
+async fn start_http_server(
+    vault : Arc<Vault>,
+    watcher: Arc<VaultWatcher>,
+    search: Arc<SearchIndex>,
+    port : u16,
+    auth_token: Option<String>,
+    tls: Option<(PathBuf, PathBuf)>,
+    policy: Option<Arc<Policy>>,
+) -> Result<QuitReason, AppError> {
     let addr = format!("0.0.0.0:{}", port);
     log::info!("Starting MCP server in HTTP mode with addr: {}. Use Ctrl+C to exit.", addr);
 
+    let tls_acceptor = match tls {
+        Some((cert_path, key_path)) => {
+            log::info!("TLS enabled for the HTTP transport.");
+            Some(load_tls_acceptor(&cert_path, &key_path)?)
+        }
+        None => None,
+    };
+
     let service = TowerToHyperService::new(
-        StreamableHttpService::new(
-            move || Ok(ObsidianMCP::new(vault.clone())),
-            LocalSessionManager::default().into(),
-            Default::default(),
-    ));
+        ServiceBuilder::new()
+            .layer(BearerAuthLayer::new(auth_token))
+            .service(StreamableHttpService::new(
+                move || Ok(ObsidianMCP::new(vault.clone(), watcher.clone(), search.clone(), policy.clone())),
+                LocalSessionManager::default().into(),
+                Default::default(),
+            )),
+    );
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
 
     loop {
-        let io = tokio::select! {
+        let stream = tokio::select! {
             _ = tokio::signal::ctrl_c() => break,
-            accept = listener.accept() => {
-                TokioIo::new(accept?.0)
-            }
+            accept = listener.accept() => accept?.0,
         };
 
         let service = service.clone();
+        let tls_acceptor = tls_acceptor.clone();
         tokio::spawn(async move {
-            let _result = Builder::new(TokioExecutor::default())
-                .serve_connection(io, service)
-                .await;
+            match tls_acceptor {
+                Some(acceptor) => match acceptor.accept(stream).await {
+                    Ok(tls_stream) => {
+                        let io = TokioIo::new(tls_stream);
+                        let _result = Builder::new(TokioExecutor::default())
+                            .serve_connection(io, service)
+                            .await;
+                    }
+                    Err(err) => tracing::error!("TLS handshake error: {:?}", err),
+                },
+                None => {
+                    let io = TokioIo::new(stream);
+                    let _result = Builder::new(TokioExecutor::default())
+                        .serve_connection(io, service)
+                        .await;
+                }
+            }
         });
     }
 
@@ -200,28 +357,48 @@ async fn main() -> Result<(), AppError> {
         )
         .try_init()?;
 
-   let vault_path = match args.vault_path {
-        Some(path) => {
-            let canonicalized_vault_path = path.canonicalize();
-
-            match canonicalized_vault_path {
-                Ok(path) => { path }
-                Err(err) => { return Err(AppError::vault_path_error(err)) }
-            }
+    // The local backend reads/writes a real directory, so it must exist and
+    // gets canonicalized. The s3/gcs backends don't live on a local
+    // filesystem at all; vault_path there is only a virtual root used to
+    // normalize note keys, so it doesn't need to exist on disk.
+    let vault_path = match args.backend.as_str() {
+        "local" => {
+            let path = args.vault_path.clone().ok_or_else(|| {
+                AppError::argument_error("--vault-path is required for the local backend")
+            })?;
+            path.canonicalize().map_err(AppError::vault_path_error)?
         }
-        None => { return Err(AppError::unexpected_error("vault path is required")) }
+        _ => args.vault_path.clone().unwrap_or_else(|| PathBuf::from("/")),
     };
 
-    let vault = Arc::new(Vault::new(vault_path).unwrap());
+    let backend = build_backend(&args, &vault_path)?;
+    let vault = Arc::new(Vault::new(vault_path, backend).unwrap());
+    let policy = build_policy(&args)?;
+
+    // Only the local backend has a directory `notify` can actually watch;
+    // s3/gcs get a no-op watcher so external-edit detection is skipped
+    // instead of silently watching an unrelated local path.
+    let watcher = Arc::new(match args.backend.as_str() {
+        "local" => VaultWatcher::spawn(vault.clone()).map_err(|err| AppError::unexpected_error(err.to_string()))?,
+        _ => VaultWatcher::noop().map_err(|err| AppError::unexpected_error(err.to_string()))?,
+    });
+    let search = Arc::new(SearchIndex::new(vault.clone()));
+    spawn_search_invalidation_task(watcher.clone(), search.clone());
 
     match args.transport.as_str() {
         #[cfg(feature = "stdio")]
         "stdio" => {
-            start_stdio_server(vault).await?;
+            start_stdio_server(vault, watcher, search, policy).await?;
         }
         #[cfg(feature = "http")]
         "http" => {
-            start_http_server(vault, args.port).await?;
+            let tls = match (args.tls_cert.clone(), args.tls_key.clone()) {
+                (Some(cert), Some(key)) => Some((cert, key)),
+                (None, None) => None,
+                _ => return Err(AppError::argument_error("--tls-cert and --tls-key must be supplied together")),
+            };
+
+            start_http_server(vault, watcher, search, args.port, args.auth_token.clone(), tls, policy).await?;
         }
         transport => {
             return handle_transport_arg_error(transport);
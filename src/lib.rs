@@ -7,5 +7,6 @@
 //! - `stdio` - Standard input/output
 //! - `http` - HTTP server support (enabled by default)
 
+pub mod auth;
 pub mod mcp;
 pub mod vault;
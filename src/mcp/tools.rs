@@ -1,17 +1,32 @@
 use std::borrow::Cow;
+use crate::vault::policy::{Operation, Policy};
+use crate::vault::search::{tokenize, SearchIndex, SearchQuery};
 use crate::vault::vault::{Vault};
+use crate::vault::watcher::VaultWatcher;
 use rmcp::{
     ErrorData as McpError, RoleServer, ServerHandler,
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
     model::*,
     schemars,
-    service::RequestContext,
+    service::{Peer, RequestContext},
     tool, tool_handler, tool_router,
 };
+use std::collections::HashSet;
 use std::sync::Arc;
 use serde_json::Value;
+use tokio::sync::Mutex;
 use tracing::instrument;
 
+const RESOURCE_URI_PREFIX: &str = "obsidian://note/";
+
+fn resource_uri(path: &str) -> String {
+    format!("{}{}", RESOURCE_URI_PREFIX, path)
+}
+
+fn path_from_resource_uri(uri: &str) -> Option<&str> {
+    uri.strip_prefix(RESOURCE_URI_PREFIX)
+}
+
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub struct ReadNoteRequest {
     #[schemars(description = "the path to the note")]
@@ -34,10 +49,33 @@ pub struct ModifyNoteRequest {
     pub content: String,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListNotesRequest {
+    #[schemars(description = "only list notes under this path prefix")]
+    #[serde(default)]
+    pub path_prefix: String,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct SearchNotesRequest {
+    #[schemars(description = "free-text search terms")]
+    #[serde(default)]
+    pub query: String,
+    #[schemars(description = "only match notes tagged with all of these frontmatter tags")]
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[schemars(description = "only match notes whose path starts with this prefix")]
+    pub path_prefix: Option<String>,
+}
+
+#[derive(Clone)]
 pub struct ObsidianMCP {
     tool_router: ToolRouter<ObsidianMCP>,
     vault_operations: Arc<Vault>,
+    watcher: Arc<VaultWatcher>,
+    subscriptions: Arc<Mutex<HashSet<String>>>,
+    policy: Option<Arc<Policy>>,
+    search: Arc<SearchIndex>,
 }
 
 pub struct ToolError {
@@ -48,18 +86,78 @@ impl ToolError {
     pub fn path_not_found() -> McpError {
         McpError::invalid_request("path cannot be empty", None)
     }
+
+    pub fn not_permitted_by_policy() -> McpError {
+        McpError::invalid_request("operation not permitted by policy", None)
+    }
 }
 
 #[tool_router]
 impl ObsidianMCP {
 
-    pub fn new(vault_operations: Arc<Vault>) -> Self {
+    pub fn new(
+        vault_operations: Arc<Vault>,
+        watcher: Arc<VaultWatcher>,
+        search: Arc<SearchIndex>,
+        policy: Option<Arc<Policy>>,
+    ) -> Self {
         Self {
+            search,
             tool_router: Self::tool_router(),
-            vault_operations: vault_operations,
+            vault_operations,
+            watcher,
+            subscriptions: Arc::new(Mutex::new(HashSet::new())),
+            policy,
         }
     }
 
+    /// Refresh the cached search index entry for `path` after a write, so
+    /// the next search reflects the note's latest content.
+    async fn invalidate_search_index(&self, path: &str) {
+        if let Ok(normalized) = self.vault_operations.normalize_path(path) {
+            self.search.invalidate(&normalized).await;
+        }
+    }
+
+    /// Consult the configured policy, if any, for `operation` over `path`,
+    /// after normalizing it the same way the vault does.
+    fn check_policy(&self, operation: Operation, path: &str) -> Result<(), McpError> {
+        let Some(policy) = &self.policy else {
+            return Ok(());
+        };
+
+        let normalized_path = self
+            .vault_operations
+            .normalize_path(path)
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+
+        if policy.is_allowed(operation, &normalized_path) {
+            Ok(())
+        } else {
+            Err(ToolError::not_permitted_by_policy())
+        }
+    }
+
+    /// Forward debounced vault changes to subscribed resources on this session's peer.
+    fn spawn_resource_update_task(&self, peer: Peer<RoleServer>) {
+        let mut changes = self.watcher.subscribe();
+        let subscriptions = self.subscriptions.clone();
+
+        tokio::spawn(async move {
+            while let Ok(changed_paths) = changes.recv().await {
+                let subscribed = subscriptions.lock().await;
+                for path in &changed_paths {
+                    let uri = resource_uri(path);
+                    if subscribed.contains(&uri) {
+                        let _ = peer
+                            .notify_resource_updated(ResourceUpdatedNotificationParam { uri })
+                            .await;
+                    }
+                }
+            }
+        });
+    }
+
     #[tool(description = "Read a note from the current vault")]
     #[instrument()]
     async fn read_note(
@@ -70,6 +168,8 @@ impl ObsidianMCP {
             return Err(ToolError::path_not_found());
         }
 
+        self.check_policy(Operation::Read, &path)?;
+
         match self.vault_operations.read_note(&path).await {
             Ok(content) => return Ok(CallToolResult::success(vec![Content::text(content)])),
             Err(err) => return Err(McpError::internal_error(err.to_string(), None)),
@@ -86,8 +186,13 @@ impl ObsidianMCP {
             return Err(ToolError::path_not_found());
         }
 
+        self.check_policy(Operation::Write, &path)?;
+
         match self.vault_operations.write_note(&path, &content).await {
-            Ok(_) => return Ok(CallToolResult::success(vec![Content::text("Note written successfully")])),
+            Ok(_) => {
+                self.invalidate_search_index(&path).await;
+                return Ok(CallToolResult::success(vec![Content::text("Note written successfully")]));
+            }
             Err(err) => return Err(McpError::internal_error(err.to_string(), None)),
         }
     }
@@ -102,11 +207,67 @@ impl ObsidianMCP {
             return Err(ToolError::path_not_found());
         }
 
+        self.check_policy(Operation::Write, &path)?;
+
         match self.vault_operations.modify_note(&path, &content).await {
-            Ok(_) => return Ok(CallToolResult::success(vec![Content::text("Note modified successfully")])),
+            Ok(_) => {
+                self.invalidate_search_index(&path).await;
+                return Ok(CallToolResult::success(vec![Content::text("Note modified successfully")]));
+            }
             Err(err) => return Err(McpError::internal_error(err.to_string(), None)),
         }
     }
+
+    #[tool(description = "List notes in the current vault")]
+    #[instrument()]
+    async fn list_notes(
+        &self,
+        Parameters(ListNotesRequest { path_prefix }): Parameters<ListNotesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.vault_operations.list_notes(&path_prefix).await {
+            Ok(paths) => {
+                let paths: Vec<String> = paths
+                    .into_iter()
+                    .filter(|path| self.check_policy(Operation::Read, path).is_ok())
+                    .collect();
+
+                Ok(CallToolResult::success(vec![Content::text(paths.join("\n"))]))
+            }
+            Err(err) => Err(McpError::internal_error(err.to_string(), None)),
+        }
+    }
+
+    #[tool(description = "Search notes by free text, frontmatter tags, and path prefix")]
+    #[instrument()]
+    async fn search_notes(
+        &self,
+        Parameters(SearchNotesRequest { query, tags, path_prefix }): Parameters<SearchNotesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Some(path_prefix) = &path_prefix {
+            self.check_policy(Operation::Read, path_prefix)?;
+        }
+
+        let search_query = SearchQuery {
+            terms: tokenize(&query),
+            tags,
+            path_prefix,
+        };
+
+        let matches = self
+            .search
+            .search(&search_query)
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+
+        let summary = matches
+            .iter()
+            .filter(|m| self.check_policy(Operation::Read, &m.path).is_ok())
+            .map(|m| format!("{} (score {:.2}): {}", m.path, m.score, m.snippet))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(CallToolResult::success(vec![Content::text(summary)]))
+    }
 }
 
 #[tool_handler()]
@@ -120,17 +281,96 @@ impl ServerHandler for ObsidianMCP {
             let initialize_headers = &http_request_part.headers;
             let initialize_uri = &http_request_part.uri;
             tracing::info!(?initialize_headers, %initialize_uri, "initialize from http server");
+
+            if let Some(identity) = http_request_part.extensions.get::<crate::auth::AuthenticatedIdentity>() {
+                tracing::info!(?identity, "initialize from authenticated session");
+            }
         }
+
+        self.spawn_resource_update_task(context.peer.clone());
+
         Ok(self.get_info())
     }
 
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let paths = self
+            .vault_operations
+            .list_notes("")
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+
+        let resources = paths
+            .into_iter()
+            .filter(|path| self.check_policy(Operation::Read, path).is_ok())
+            .map(|path| Resource::new(RawResource::new(resource_uri(&path), path), None))
+            .collect();
+
+        Ok(ListResourcesResult {
+            resources,
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let path = path_from_resource_uri(&request.uri)
+            .ok_or_else(|| McpError::invalid_request("unknown resource uri", None))?;
+
+        self.check_policy(Operation::Read, path)?;
+
+        let content = self
+            .vault_operations
+            .read_note(path)
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::text(content, request.uri)],
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        if let Some(path) = path_from_resource_uri(&request.uri) {
+            self.check_policy(Operation::Read, path)?;
+        }
+
+        self.subscriptions.lock().await.insert(request.uri);
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        self.subscriptions.lock().await.remove(&request.uri);
+        Ok(())
+    }
+
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .build(),
             server_info: Implementation::from_build_env(),
             instructions: Some(
-                "This server provides Obsidian Vault mcp. Tools: read_note, write_note, modify_note.".to_string(),
+                "This server provides Obsidian Vault mcp. Tools: read_note, write_note, modify_note, \
+                 list_notes, search_notes. Resources: obsidian://note/<path>, subscribe for live update \
+                 notifications."
+                    .to_string(),
             ),
         }
     }
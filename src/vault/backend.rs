@@ -0,0 +1,86 @@
+use crate::vault::error::VaultError;
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+/// Uniform storage interface consulted by [`crate::vault::vault::Vault`] once
+/// a note path has already been normalized and traversal-checked.
+///
+/// Keys passed to a backend are always vault-relative (e.g. `journal/2024-01-01.md`),
+/// never absolute filesystem paths, so the same trait can be backed by a local
+/// directory or a remote object store.
+#[async_trait]
+pub trait VaultBackend: Send + Sync {
+    async fn read(&self, key: &str) -> Result<String, VaultError>;
+
+    async fn write(&self, key: &str, content: &str) -> Result<(), VaultError>;
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, VaultError>;
+
+    async fn exists(&self, key: &str) -> Result<bool, VaultError>;
+}
+
+/// Backend storing notes as plain files under `vault_path` on the local filesystem.
+#[derive(Clone, Debug)]
+pub struct LocalBackend {
+    vault_path: PathBuf,
+}
+
+impl LocalBackend {
+    pub fn new(vault_path: PathBuf) -> Self {
+        Self { vault_path }
+    }
+
+    fn resolve(&self, key: &str) -> PathBuf {
+        self.vault_path.join(key)
+    }
+}
+
+#[async_trait]
+impl VaultBackend for LocalBackend {
+    async fn read(&self, key: &str) -> Result<String, VaultError> {
+        tokio::fs::read_to_string(self.resolve(key))
+            .await
+            .map_err(VaultError::io)
+    }
+
+    async fn write(&self, key: &str, content: &str) -> Result<(), VaultError> {
+        let resolved_path = self.resolve(key);
+        // TODO: create not existing directories
+        tokio::fs::write(&resolved_path, content)
+            .await
+            .map_err(VaultError::io)
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, VaultError> {
+        let root = self.resolve(prefix);
+        let mut entries = Vec::new();
+        let mut stack = vec![root.clone()];
+
+        while let Some(dir) = stack.pop() {
+            let mut read_dir = match tokio::fs::read_dir(&dir).await {
+                Ok(read_dir) => read_dir,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(VaultError::io(err)),
+            };
+
+            while let Some(entry) = read_dir.next_entry().await.map_err(VaultError::io)? {
+                let path = entry.path();
+                let file_type = entry.file_type().await.map_err(VaultError::io)?;
+
+                if file_type.is_dir() {
+                    stack.push(path);
+                } else if let Ok(relative) = path.strip_prefix(&self.vault_path) {
+                    entries.push(relative.to_string_lossy().into_owned());
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, VaultError> {
+        Ok(tokio::fs::try_exists(self.resolve(key))
+            .await
+            .map_err(VaultError::io)?)
+    }
+}
@@ -9,6 +9,9 @@ pub enum VaultError {
 
     /// Invalid path
     InvalidPath { reason: String },
+
+    /// Operation not implemented by the configured backend
+    Unsupported { reason: String },
 }
 
 impl fmt::Display for VaultError {
@@ -16,6 +19,7 @@ impl fmt::Display for VaultError {
         match self {
             VaultError::IoError(err) => write!(f, "IO error: {}", err),
             VaultError::InvalidPath { reason } => write!(f, "Invalid path: {}", reason),
+            VaultError::Unsupported { reason } => write!(f, "Unsupported operation: {}", reason),
         }
     }
 }
@@ -36,4 +40,10 @@ impl VaultError {
             reason: format!("Invalid Path: path traversal detected: {:?}", path),
         }
     }
+
+    pub fn unsupported(reason: impl Into<String>) -> Self {
+        VaultError::Unsupported {
+            reason: reason.into(),
+        }
+    }
 }
@@ -0,0 +1,7 @@
+pub mod backend;
+pub mod error;
+pub mod object_store_backend;
+pub mod policy;
+pub mod search;
+pub mod vault;
+pub mod watcher;
@@ -0,0 +1,132 @@
+use crate::vault::backend::VaultBackend;
+use crate::vault::error::VaultError;
+use async_trait::async_trait;
+
+/// Static credentials for an S3/GCS-compatible object store.
+///
+/// Modeled after the way `unftp`'s storage backends expose a remote bucket
+/// through a uniform file API: the backend only needs an endpoint, a bucket
+/// and a way to sign requests, everything else reads and writes like a path.
+#[derive(Clone)]
+pub struct ObjectStoreCredentials {
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+impl std::fmt::Debug for ObjectStoreCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectStoreCredentials")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Backend storing notes as objects in an S3/GCS-style bucket.
+///
+/// Vault-relative keys are mapped to object keys under `prefix`, e.g. a note
+/// at `journal/today.md` with `prefix = "vault"` becomes the object
+/// `vault/journal/today.md`.
+#[derive(Clone)]
+pub struct ObjectStoreBackend {
+    endpoint: String,
+    bucket: String,
+    prefix: String,
+    credentials: ObjectStoreCredentials,
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for ObjectStoreBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectStoreBackend")
+            .field("endpoint", &self.endpoint)
+            .field("bucket", &self.bucket)
+            .field("prefix", &self.prefix)
+            .field("credentials", &self.credentials)
+            .finish()
+    }
+}
+
+impl ObjectStoreBackend {
+    pub fn new(
+        endpoint: impl Into<String>,
+        bucket: impl Into<String>,
+        prefix: impl Into<String>,
+        credentials: ObjectStoreCredentials,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            bucket: bucket.into(),
+            prefix: prefix.into(),
+            credentials,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn object_url(&self, key: &str) -> String {
+        let prefix = self.prefix.trim_matches('/');
+        if prefix.is_empty() {
+            format!("{}/{}/{}", self.endpoint, self.bucket, key)
+        } else {
+            format!("{}/{}/{}/{}", self.endpoint, self.bucket, prefix, key)
+        }
+    }
+
+    fn authorized(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        // TODO: sign requests with SigV4 instead of basic auth once we pick an SDK
+        builder.basic_auth(&self.credentials.access_key, Some(&self.credentials.secret_key))
+    }
+}
+
+#[async_trait]
+impl VaultBackend for ObjectStoreBackend {
+    async fn read(&self, key: &str) -> Result<String, VaultError> {
+        let response = self
+            .authorized(self.client.get(self.object_url(key)))
+            .send()
+            .await
+            .map_err(|err| VaultError::invalid_path(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| VaultError::invalid_path(err.to_string()))?;
+
+        response
+            .text()
+            .await
+            .map_err(|err| VaultError::invalid_path(err.to_string()))
+    }
+
+    async fn write(&self, key: &str, content: &str) -> Result<(), VaultError> {
+        let stream = futures_util::stream::once(async move { Ok::<_, std::io::Error>(content.as_bytes().to_vec()) });
+
+        self.authorized(self.client.put(self.object_url(key)))
+            .body(reqwest::Body::wrap_stream(stream))
+            .send()
+            .await
+            .map_err(|err| VaultError::invalid_path(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| VaultError::invalid_path(err.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, VaultError> {
+        // TODO: implement bucket listing (with continuation-token pagination)
+        // once we're on a real SDK. Returning Ok(vec![]) here would make
+        // list_notes/list_resources/search_notes silently report an empty
+        // vault instead of failing loudly.
+        let _ = prefix;
+        Err(VaultError::unsupported(
+            "listing notes is not implemented for the s3/gcs backend",
+        ))
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, VaultError> {
+        let response = self
+            .authorized(self.client.head(self.object_url(key)))
+            .send()
+            .await
+            .map_err(|err| VaultError::invalid_path(err.to_string()))?;
+
+        Ok(response.status().is_success())
+    }
+}
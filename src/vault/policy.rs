@@ -0,0 +1,190 @@
+use crate::vault::error::VaultError;
+use glob::Pattern;
+use serde::Deserialize;
+use std::path::Path;
+
+/// Operation kind a policy allowlist entry grants, mirroring the tool surface
+/// `ObsidianMCP` exposes (`read_note` vs `write_note`/`modify_note`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Operation {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Deserialize)]
+struct AllowEntryConfig {
+    operation: Operation,
+    glob: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PolicyConfig {
+    #[serde(default)]
+    read_only: bool,
+    #[serde(default)]
+    allow: Vec<AllowEntryConfig>,
+}
+
+struct AllowEntry {
+    operation: Operation,
+    pattern: Pattern,
+}
+
+/// Capability policy consulted by `ObsidianMCP` tools after a path has
+/// already been resolved through `Vault::normalize_path`. Operators opt in
+/// with `--policy <file>` or the `--read-only` shorthand; when neither flag
+/// is set no policy is constructed and every operation is permitted.
+pub struct Policy {
+    read_only: bool,
+    allow: Vec<AllowEntry>,
+}
+
+impl Policy {
+    /// Shorthand policy that denies every write, regardless of path.
+    pub fn read_only() -> Self {
+        Self {
+            read_only: true,
+            allow: Vec::new(),
+        }
+    }
+
+    /// Load a policy from a TOML file, e.g.:
+    ///
+    /// ```toml
+    /// read_only = false
+    ///
+    /// [[allow]]
+    /// operation = "read"
+    /// glob = "journal/**"
+    ///
+    /// [[allow]]
+    /// operation = "write"
+    /// glob = "journal/**"
+    /// ```
+    pub fn from_file(path: &Path) -> Result<Self, VaultError> {
+        let content = std::fs::read_to_string(path).map_err(VaultError::io)?;
+
+        let config: PolicyConfig = toml::from_str(&content)
+            .map_err(|err| VaultError::invalid_path(format!("invalid policy file: {}", err)))?;
+
+        let allow = config
+            .allow
+            .into_iter()
+            .map(|entry| {
+                Pattern::new(&entry.glob)
+                    .map(|pattern| AllowEntry {
+                        operation: entry.operation,
+                        pattern,
+                    })
+                    .map_err(|err| {
+                        VaultError::invalid_path(format!("invalid glob {:?}: {}", entry.glob, err))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            read_only: config.read_only,
+            allow,
+        })
+    }
+
+    /// Returns `true` if `operation` is permitted over the already-normalized
+    /// vault-relative path `relative_path`.
+    pub fn is_allowed(&self, operation: Operation, relative_path: &str) -> bool {
+        if self.read_only && operation == Operation::Write {
+            return false;
+        }
+
+        if self.allow.is_empty() {
+            return true;
+        }
+
+        self.allow
+            .iter()
+            .any(|entry| entry.operation == operation && entry.pattern.matches(relative_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn should_permit_everything_when_no_policy_constraints() {
+        let policy = Policy {
+            read_only: false,
+            allow: Vec::new(),
+        };
+
+        assert!(policy.is_allowed(Operation::Read, "journal/today.md"));
+        assert!(policy.is_allowed(Operation::Write, "journal/today.md"));
+    }
+
+    #[test]
+    fn read_only_denies_writes_but_allows_reads() {
+        let policy = Policy::read_only();
+
+        assert!(policy.is_allowed(Operation::Read, "journal/today.md"));
+        assert!(!policy.is_allowed(Operation::Write, "journal/today.md"));
+    }
+
+    #[test]
+    fn allow_list_scopes_by_operation_and_glob() {
+        let policy = Policy {
+            read_only: false,
+            allow: vec![AllowEntry {
+                operation: Operation::Read,
+                pattern: Pattern::new("journal/**").unwrap(),
+            }],
+        };
+
+        assert!(policy.is_allowed(Operation::Read, "journal/today.md"));
+        assert!(!policy.is_allowed(Operation::Read, "secrets/today.md"));
+        assert!(!policy.is_allowed(Operation::Write, "journal/today.md"));
+    }
+
+    #[test]
+    fn from_file_parses_toml_policy() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"
+            read_only = false
+
+            [[allow]]
+            operation = "read"
+            glob = "journal/**"
+
+            [[allow]]
+            operation = "write"
+            glob = "journal/**"
+            "#
+        )
+        .unwrap();
+
+        let policy = Policy::from_file(file.path()).unwrap();
+
+        assert!(policy.is_allowed(Operation::Read, "journal/today.md"));
+        assert!(policy.is_allowed(Operation::Write, "journal/today.md"));
+        assert!(!policy.is_allowed(Operation::Read, "secrets/today.md"));
+    }
+
+    #[test]
+    fn from_file_rejects_invalid_glob() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"
+            [[allow]]
+            operation = "read"
+            glob = "["
+            "#
+        )
+        .unwrap();
+
+        assert!(Policy::from_file(file.path()).is_err());
+    }
+}
@@ -0,0 +1,375 @@
+use crate::vault::error::VaultError;
+use crate::vault::vault::Vault;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Extra weight given to a term found in a note's frontmatter tags or title,
+/// on top of its raw occurrence count in the body.
+const FRONTMATTER_TAG_BOOST: usize = 2;
+const TITLE_BOOST: usize = 3;
+
+const SNIPPET_CHARS: usize = 160;
+const SNIPPET_CONTEXT_CHARS: usize = 40;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct Frontmatter {
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedNote {
+    path: String,
+    tags: Vec<String>,
+    body: String,
+    term_counts: HashMap<String, usize>,
+}
+
+/// A search hit: the note's path, its TF-style score, and a snippet of body
+/// text around the first matched term.
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+    pub path: String,
+    pub score: f32,
+    pub snippet: String,
+}
+
+/// Free-text terms, required frontmatter tags, and an optional path-prefix
+/// scope to run a search over the indexed vault.
+#[derive(Debug, Default, Clone)]
+pub struct SearchQuery {
+    pub terms: Vec<String>,
+    pub tags: Vec<String>,
+    pub path_prefix: Option<String>,
+}
+
+/// Lazily-built full-text and frontmatter index over a vault's notes.
+///
+/// The index is built on the first call to `search` and kept up to date by
+/// callers invoking `invalidate` after a note is written, rather than being
+/// rebuilt from scratch on every write.
+pub struct SearchIndex {
+    vault: Arc<Vault>,
+    notes: RwLock<Option<HashMap<String, IndexedNote>>>,
+}
+
+impl SearchIndex {
+    pub fn new(vault: Arc<Vault>) -> Self {
+        Self {
+            vault,
+            notes: RwLock::new(None),
+        }
+    }
+
+    pub async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchMatch>, VaultError> {
+        self.ensure_built().await?;
+
+        let notes = self.notes.read().await;
+        let notes = notes.as_ref().expect("index was just built above");
+
+        let mut matches: Vec<SearchMatch> = notes
+            .values()
+            .filter(|note| matches_prefix(note, query) && matches_tags(note, query))
+            .filter_map(|note| {
+                score(note, query).map(|score| SearchMatch {
+                    path: note.path.clone(),
+                    score,
+                    snippet: snippet(note, query),
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+        Ok(matches)
+    }
+
+    /// Re-read `path` from the backend and refresh its entry so the next
+    /// search reflects its latest content, removing it if it no longer exists.
+    pub async fn invalidate(&self, path: &str) {
+        let mut notes = self.notes.write().await;
+        let Some(notes) = notes.as_mut() else {
+            return;
+        };
+
+        match self.vault.read_note(path).await {
+            Ok(content) => {
+                notes.insert(path.to_string(), index_note(path, &content));
+            }
+            Err(_) => {
+                notes.remove(path);
+            }
+        }
+    }
+
+    async fn ensure_built(&self) -> Result<(), VaultError> {
+        if self.notes.read().await.is_some() {
+            return Ok(());
+        }
+
+        let mut notes = self.notes.write().await;
+        if notes.is_some() {
+            return Ok(());
+        }
+
+        let paths = self.vault.list_notes("").await?;
+        let mut indexed = HashMap::with_capacity(paths.len());
+
+        for path in paths {
+            if let Ok(content) = self.vault.read_note(&path).await {
+                indexed.insert(path.clone(), index_note(&path, &content));
+            }
+        }
+
+        *notes = Some(indexed);
+        Ok(())
+    }
+}
+
+/// Split words on anything that isn't alphanumeric and lowercase them, so
+/// queries and indexed notes tokenize the same way.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.to_lowercase())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
+    if let Some(rest) = content.strip_prefix("---\n") {
+        if let Some(end) = rest.find("\n---") {
+            let body = rest[end + 4..].trim_start_matches('\n');
+            return (Some(&rest[..end]), body);
+        }
+    }
+
+    (None, content)
+}
+
+fn index_note(path: &str, content: &str) -> IndexedNote {
+    let (frontmatter_text, body) = split_frontmatter(content);
+    let frontmatter: Frontmatter = frontmatter_text
+        .and_then(|text| serde_yaml::from_str(text).ok())
+        .unwrap_or_default();
+
+    let title = Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string());
+
+    let mut term_counts: HashMap<String, usize> = HashMap::new();
+    for term in tokenize(body) {
+        *term_counts.entry(term).or_insert(0) += 1;
+    }
+    for term in tokenize(&title) {
+        *term_counts.entry(term).or_insert(0) += TITLE_BOOST;
+    }
+    for tag in &frontmatter.tags {
+        for term in tokenize(tag) {
+            *term_counts.entry(term).or_insert(0) += FRONTMATTER_TAG_BOOST;
+        }
+    }
+
+    IndexedNote {
+        path: path.to_string(),
+        tags: frontmatter.tags,
+        body: body.to_string(),
+        term_counts,
+    }
+}
+
+fn matches_prefix(note: &IndexedNote, query: &SearchQuery) -> bool {
+    match &query.path_prefix {
+        Some(prefix) => note.path.starts_with(prefix.as_str()),
+        None => true,
+    }
+}
+
+fn matches_tags(note: &IndexedNote, query: &SearchQuery) -> bool {
+    query
+        .tags
+        .iter()
+        .all(|tag| note.tags.iter().any(|note_tag| note_tag.eq_ignore_ascii_case(tag)))
+}
+
+fn score(note: &IndexedNote, query: &SearchQuery) -> Option<f32> {
+    if query.terms.is_empty() {
+        return Some(0.0);
+    }
+
+    let mut total = 0;
+    let mut any_match = false;
+
+    for term in &query.terms {
+        if let Some(count) = note.term_counts.get(term) {
+            any_match = true;
+            total += count;
+        }
+    }
+
+    any_match.then_some(total as f32)
+}
+
+fn snippet(note: &IndexedNote, query: &SearchQuery) -> String {
+    let lower_body = note.body.to_lowercase();
+    let matched_byte_pos = query.terms.iter().find_map(|term| lower_body.find(term.as_str()));
+
+    let chars: Vec<char> = note.body.chars().collect();
+
+    match matched_byte_pos.map(|byte_pos| lower_body[..byte_pos].chars().count()) {
+        Some(char_pos) => {
+            // Unicode case expansion (e.g. Turkish dotted capital I) can make
+            // `lower_body` longer in chars than `body`, so clamp before using
+            // `char_pos` to index into `chars` to avoid a start > end panic.
+            let char_pos = char_pos.min(chars.len());
+            let start = char_pos.saturating_sub(SNIPPET_CONTEXT_CHARS).min(chars.len());
+            let end = (char_pos + SNIPPET_CONTEXT_CHARS * 3).min(chars.len());
+            chars[start..end].iter().collect::<String>().trim().to_string()
+        }
+        None => chars.into_iter().take(SNIPPET_CHARS).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::backend::LocalBackend;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn tokenize_splits_on_non_alphanumeric_and_lowercases() {
+        assert_eq!(
+            tokenize("Hello, World! foo-bar_baz"),
+            vec!["hello", "world", "foo", "bar", "baz"]
+        );
+        assert!(tokenize("   ").is_empty());
+    }
+
+    #[test]
+    fn split_frontmatter_separates_yaml_header_from_body() {
+        let content = "---\ntags:\n  - work\n---\nHello body";
+        let (frontmatter, body) = split_frontmatter(content);
+        assert_eq!(frontmatter, Some("tags:\n  - work"));
+        assert_eq!(body, "Hello body");
+    }
+
+    #[test]
+    fn split_frontmatter_returns_whole_content_when_no_delimiter() {
+        let content = "just a note with no frontmatter";
+        let (frontmatter, body) = split_frontmatter(content);
+        assert_eq!(frontmatter, None);
+        assert_eq!(body, content);
+    }
+
+    #[test]
+    fn index_note_boosts_title_and_frontmatter_tags() {
+        let content = "---\ntags:\n  - rust\n---\nrust is great";
+        let note = index_note("projects/rust.md", content);
+
+        assert_eq!(note.tags, vec!["rust".to_string()]);
+        // "rust" appears once in the body, once as the title, and once as a tag.
+        assert_eq!(note.term_counts.get("rust"), Some(&(1 + TITLE_BOOST + FRONTMATTER_TAG_BOOST)));
+        assert_eq!(note.term_counts.get("great"), Some(&1));
+    }
+
+    #[test]
+    fn matches_prefix_and_tags_filter_as_expected() {
+        let note = index_note("journal/today.md", "---\ntags:\n  - diary\n---\nhello");
+
+        let prefix_query = SearchQuery {
+            path_prefix: Some("journal".to_string()),
+            ..Default::default()
+        };
+        assert!(matches_prefix(&note, &prefix_query));
+
+        let wrong_prefix_query = SearchQuery {
+            path_prefix: Some("work".to_string()),
+            ..Default::default()
+        };
+        assert!(!matches_prefix(&note, &wrong_prefix_query));
+
+        let tag_query = SearchQuery {
+            tags: vec!["diary".to_string()],
+            ..Default::default()
+        };
+        assert!(matches_tags(&note, &tag_query));
+
+        let missing_tag_query = SearchQuery {
+            tags: vec!["work".to_string()],
+            ..Default::default()
+        };
+        assert!(!matches_tags(&note, &missing_tag_query));
+    }
+
+    #[test]
+    fn score_sums_matched_term_counts_and_matches_everything_with_no_terms() {
+        let note = index_note("notes/rust.md", "rust rust programming");
+
+        let query = SearchQuery {
+            terms: tokenize("rust"),
+            ..Default::default()
+        };
+        assert_eq!(score(&note, &query), Some((2 + TITLE_BOOST) as f32));
+
+        let no_match_query = SearchQuery {
+            terms: tokenize("python"),
+            ..Default::default()
+        };
+        assert_eq!(score(&note, &no_match_query), None);
+
+        assert_eq!(score(&note, &SearchQuery::default()), Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn search_finds_notes_by_term_and_respects_invalidate() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("journal")).unwrap();
+        fs::write(temp_dir.path().join("journal/today.md"), "writing about rust").unwrap();
+
+        let backend = Arc::new(LocalBackend::new(temp_dir.path().to_path_buf()));
+        let vault = Arc::new(Vault::new(temp_dir.path().to_path_buf(), backend).unwrap());
+        let index = SearchIndex::new(vault.clone());
+
+        let rust_query = SearchQuery {
+            terms: tokenize("rust"),
+            ..Default::default()
+        };
+        let matches = index.search(&rust_query).await.unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].path, "journal/today.md");
+
+        // Invalidating after an edit must re-index the note's new content,
+        // not just drop it from the map until the process restarts.
+        fs::write(temp_dir.path().join("journal/today.md"), "nothing relevant here").unwrap();
+        index.invalidate("journal/today.md").await;
+
+        let matches = index.search(&rust_query).await.unwrap();
+        assert!(matches.is_empty());
+
+        let relevant_query = SearchQuery {
+            terms: tokenize("relevant"),
+            ..Default::default()
+        };
+        let matches = index.search(&relevant_query).await.unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].path, "journal/today.md");
+    }
+
+    #[test]
+    fn snippet_does_not_panic_on_unicode_case_expansion() {
+        // Turkish dotted capital I (U+0130) lowercases to "i" + a combining
+        // dot above, so the lowercased body has more chars than the original.
+        let body = format!("{}istanbul", "İ".repeat(50));
+        let note = index_note("notes/istanbul.md", &body);
+        let query = SearchQuery {
+            terms: tokenize("istanbul"),
+            ..Default::default()
+        };
+
+        snippet(&note, &query);
+    }
+}
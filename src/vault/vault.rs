@@ -1,20 +1,23 @@
+use crate::vault::backend::VaultBackend;
 use crate::vault::error::VaultError;
 use path_absolutize::Absolutize;
 use path_trav::PathTrav;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Vault {
     vault_path: PathBuf,
+    backend: Arc<dyn VaultBackend>,
 }
 
 impl Vault {
-    /// Create a new vault
-    pub fn new(vault_path: PathBuf) -> Result<Self, ()> {
+    /// Create a new vault backed by the given storage backend
+    pub fn new(vault_path: PathBuf, backend: Arc<dyn VaultBackend>) -> Result<Self, ()> {
         let vault_path = vault_path.clone();
 
-        Ok(Self { vault_path })
+        Ok(Self { vault_path, backend })
     }
 
     /// Get vault path
@@ -23,37 +26,49 @@ impl Vault {
     }
 
     pub async fn read_note(&self, path: &str) -> Result<String, VaultError> {
-        self.read_file(path.as_ref()).await
+        let key = self.resolve_path_from_vault_root(path.as_ref())?;
+        self.backend.read(&key).await
     }
 
     pub async fn write_note(&self, path: &str, content: &str) -> Result<(), VaultError> {
-        self.write_file(path.as_ref(), content).await
+        let key = self.resolve_path_from_vault_root(path.as_ref())?;
+        self.backend.write(&key, content).await
     }
 
     pub async fn modify_note(&self, path: &str, content: &str) -> Result<(), VaultError> {
-        self.write_file(path.as_ref(), content).await
+        self.write_note(path, content).await
     }
 
-    async fn read_file(&self, path: &Path) -> Result<String, VaultError> {
-        let resolved_file_path = self.resolve_path_from_vault_root(path)?;
-        let content = tokio::fs::read_to_string(&resolved_file_path)
-            .await
-            .map_err(VaultError::io)?;
+    pub async fn list_notes(&self, prefix: &str) -> Result<Vec<String>, VaultError> {
+        let key = self.resolve_path_from_vault_root(prefix.as_ref())?;
+        self.backend.list(&key).await
+    }
 
-        Ok(content)
+    pub async fn note_exists(&self, path: &str) -> Result<bool, VaultError> {
+        let key = self.resolve_path_from_vault_root(path.as_ref())?;
+        self.backend.exists(&key).await
     }
 
-    async fn write_file(&self, path: &Path, content: &str) -> Result<(), VaultError> {
-        let resolved_file_path = self.resolve_path_from_vault_root(path)?;
-        // TODO: create not existing directories
-        tokio::fs::write(&resolved_file_path, content)
-            .await
-            .map_err(VaultError::io)?;
+    /// Normalize a vault-relative path the same way `read_note`/`write_note` do,
+    /// without touching the backend. Used by the policy layer, which must
+    /// evaluate allowlist globs against the resolved path before a tool call
+    /// reaches the backend.
+    pub fn normalize_path(&self, path: &str) -> Result<String, VaultError> {
+        self.resolve_path_from_vault_root(path.as_ref())
+    }
 
-        Ok(())
+    /// Turn an absolute filesystem path (as reported by the `notify` watcher)
+    /// into a vault-relative key, re-running the traversal check so a symlink
+    /// that resolves outside the vault root can't leak through.
+    pub fn relativize_fs_path(&self, absolute_path: &Path) -> Option<String> {
+        let relative = absolute_path.strip_prefix(&self.vault_path).ok()?;
+        self.resolve_path_from_vault_root(relative).ok()
     }
 
-    fn resolve_path_from_vault_root(&self, path: &Path) -> Result<PathBuf, VaultError> {
+    /// Normalize a vault-relative path and reject any attempt at traversing
+    /// outside the vault root, returning a backend-agnostic key that callers
+    /// hand to the configured `VaultBackend`.
+    fn resolve_path_from_vault_root(&self, path: &Path) -> Result<String, VaultError> {
         // we allow absolute paths as our vault behaves like a chroot
         let normalized_path = if path.is_absolute() {
             let mut components = path.components();
@@ -68,7 +83,17 @@ impl Vault {
 
             Ok(false) | Err(ErrorKind::NotFound) => {
                 match normalized_path.absolutize_virtually(self.vault_path.as_path()) {
-                    Ok(resolved_path) => Ok(PathBuf::from(resolved_path)),
+                    Ok(resolved_path) => {
+                        let key = resolved_path
+                            .strip_prefix(self.vault_path.as_path())
+                            .map_err(|_| {
+                                VaultError::invalid_path(format!(
+                                    "Invalid path {:?}: outside vault root",
+                                    path
+                                ))
+                            })?;
+                        Ok(key.to_string_lossy().into_owned())
+                    }
                     Err(err) => {
                         Err(VaultError::invalid_path(format!(
                             "Invalid path {:?}: {:?}",
@@ -89,6 +114,7 @@ impl Vault {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vault::backend::LocalBackend;
     use crate::vault::error::VaultError::InvalidPath;
     use std::env::set_current_dir;
     use std::fs::File;
@@ -99,23 +125,28 @@ mod tests {
 
     const FILE_WITH_TRAVERSAL_AND_EXISTS: &str = "existing-file.md";
 
+    fn test_vault(vault_path: PathBuf) -> Vault {
+        let backend = Arc::new(LocalBackend::new(vault_path.clone()));
+        Vault::new(vault_path, backend).unwrap()
+    }
+
     #[tokio::test]
     async fn should_resolve_path_from_vault_root() {
         let temp_dir = TempDir::new().unwrap();
         let temp_path = temp_dir.path();
-        let vault = Vault::new(PathBuf::from(temp_path)).unwrap();
+        let vault = test_vault(PathBuf::from(temp_path));
 
         /* no traversal */
         let path = Path::new(FILE_NO_TRAVERSAL);
         let result = vault.resolve_path_from_vault_root(path);
         assert!(result.is_ok());
-        assert_eq!(temp_path.join(FILE_NO_TRAVERSAL), result.unwrap());
+        assert_eq!(FILE_NO_TRAVERSAL, result.unwrap());
 
         /* no traversal and absolute */
         let path = Path::new("/").join(FILE_NO_TRAVERSAL);
         let result = vault.resolve_path_from_vault_root(path.as_path());
         assert!(result.is_ok());
-        assert_eq!(temp_path.join(FILE_NO_TRAVERSAL), result.unwrap());
+        assert_eq!(FILE_NO_TRAVERSAL, result.unwrap());
 
         /* not existing traversal */
         let path = Path::new(FILE_WITH_TRAVERSAL);
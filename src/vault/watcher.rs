@@ -0,0 +1,87 @@
+use crate::vault::error::VaultError;
+use crate::vault::vault::Vault;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Debounce window used to coalesce bursts of OS filesystem events (e.g. an
+/// editor doing a write-then-rename) into a single batch of changed paths.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(300);
+
+/// Watches a vault directory for external changes (notes edited outside of
+/// this server, e.g. from the Obsidian desktop app) and broadcasts debounced,
+/// vault-relative paths to every subscriber.
+pub struct VaultWatcher {
+    sender: broadcast::Sender<HashSet<String>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl VaultWatcher {
+    /// Spawn a recursive watcher over `vault.vault_path()`.
+    pub fn spawn(vault: Arc<Vault>) -> Result<Self, VaultError> {
+        let (raw_tx, mut raw_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if let Ok(event) = event {
+                let _ = raw_tx.send(event);
+            }
+        })
+        .map_err(|err| VaultError::invalid_path(err.to_string()))?;
+
+        watcher
+            .watch(vault.vault_path(), RecursiveMode::Recursive)
+            .map_err(|err| VaultError::invalid_path(err.to_string()))?;
+
+        let (sender, _) = broadcast::channel(64);
+        let debounced_sender = sender.clone();
+
+        tokio::spawn(async move {
+            let mut pending: HashSet<String> = HashSet::new();
+
+            while let Some(first) = raw_rx.recv().await {
+                collect_relative_paths(&vault, &first, &mut pending);
+
+                loop {
+                    match tokio::time::timeout(DEBOUNCE_WINDOW, raw_rx.recv()).await {
+                        Ok(Some(event)) => collect_relative_paths(&vault, &event, &mut pending),
+                        Ok(None) => return,
+                        Err(_) => break,
+                    }
+                }
+
+                if !pending.is_empty() {
+                    let _ = debounced_sender.send(std::mem::take(&mut pending));
+                }
+            }
+        });
+
+        Ok(Self { sender, _watcher: watcher })
+    }
+
+    /// A watcher that never observes any filesystem events, for backends
+    /// (e.g. s3/gcs) that aren't rooted in a local directory `notify` can
+    /// watch. Subscribers still get a valid receiver; it just never fires.
+    pub fn noop() -> Result<Self, VaultError> {
+        let watcher = notify::recommended_watcher(|_: notify::Result<notify::Event>| {})
+            .map_err(|err| VaultError::invalid_path(err.to_string()))?;
+
+        let (sender, _) = broadcast::channel(1);
+
+        Ok(Self { sender, _watcher: watcher })
+    }
+
+    /// Subscribe to batches of debounced, vault-relative changed paths.
+    pub fn subscribe(&self) -> broadcast::Receiver<HashSet<String>> {
+        self.sender.subscribe()
+    }
+}
+
+fn collect_relative_paths(vault: &Vault, event: &notify::Event, pending: &mut HashSet<String>) {
+    for path in &event.paths {
+        if let Some(relative) = vault.relativize_fs_path(path) {
+            pending.insert(relative);
+        }
+    }
+}